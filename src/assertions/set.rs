@@ -0,0 +1,208 @@
+use std::collections::{BTreeSet, HashSet};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::base::{AssertionApi, AssertionResult, Fact, ReturnStrategy, Subject};
+
+/// Assertions on `HashSet`/`BTreeSet` subjects that treat the subject as a
+/// mathematical set rather than an ordered sequence of elements. For
+/// order-sensitive checks on the same data, see
+/// [`crate::assertions::iterator::IteratorAssertion`].
+pub trait SetAssertion<'a, T, S, R> {
+    /// Asserts that every element of the subject is also in `expected`.
+    fn is_subset_of(&self, expected: &S) -> R;
+    /// Asserts that the subject contains every element of `expected`.
+    fn is_superset_of(&self, expected: &S) -> R;
+    /// Asserts that the subject and `expected` share no elements.
+    fn is_disjoint_from(&self, expected: &S) -> R;
+    /// Asserts that the subject contains every element of `expected`.
+    ///
+    /// This is an alias of [`SetAssertion::is_superset_of`] for readability
+    /// at call sites where the subject is conceptually a container being
+    /// checked for coverage rather than a set being compared to another.
+    fn contains_all_of(&self, expected: &S) -> R;
+    /// Asserts that the subject and `expected` share at least one element.
+    fn intersects_with(&self, expected: &S) -> R;
+}
+
+macro_rules! impl_set_assertion {
+    ($set:ident, $($bound:tt)+) => {
+        impl<'a, T, R> SetAssertion<'a, T, $set<T>, R> for Subject<'a, $set<T>, (), R>
+        where
+            T: $($bound)+ + Debug,
+            AssertionResult: ReturnStrategy<R>,
+        {
+            fn is_subset_of(&self, expected: &$set<T>) -> R {
+                let unexpected: $set<&T> = self.actual().difference(expected).collect();
+                if unexpected.is_empty() {
+                    self.new_result().do_ok()
+                } else {
+                    self.new_result()
+                        .add_fact(Fact::new("unexpected elements", format!("{:?}", unexpected)))
+                        .add_splitter()
+                        .add_fact(Fact::new("expected", format!("{:?}", expected)))
+                        .add_fact(Fact::new("actual", format!("{:?}", self.actual())))
+                        .do_fail()
+                }
+            }
+
+            fn is_superset_of(&self, expected: &$set<T>) -> R {
+                let missing: $set<&T> = expected.difference(self.actual()).collect();
+                if missing.is_empty() {
+                    self.new_result().do_ok()
+                } else {
+                    self.new_result()
+                        .add_fact(Fact::new("missing elements", format!("{:?}", missing)))
+                        .add_splitter()
+                        .add_fact(Fact::new("expected", format!("{:?}", expected)))
+                        .add_fact(Fact::new("actual", format!("{:?}", self.actual())))
+                        .do_fail()
+                }
+            }
+
+            fn contains_all_of(&self, expected: &$set<T>) -> R {
+                self.is_superset_of(expected)
+            }
+
+            fn is_disjoint_from(&self, expected: &$set<T>) -> R {
+                let intersection: $set<&T> = self.actual().intersection(expected).collect();
+                if intersection.is_empty() {
+                    self.new_result().do_ok()
+                } else {
+                    self.new_result()
+                        .add_fact(Fact::new(
+                            "unexpected intersection",
+                            format!("{:?}", intersection),
+                        ))
+                        .add_splitter()
+                        .add_fact(Fact::new("expected", format!("{:?}", expected)))
+                        .add_fact(Fact::new("actual", format!("{:?}", self.actual())))
+                        .do_fail()
+                }
+            }
+
+            fn intersects_with(&self, expected: &$set<T>) -> R {
+                let intersection: $set<&T> = self.actual().intersection(expected).collect();
+                if intersection.is_empty() {
+                    self.new_result()
+                        .add_simple_fact("expected an intersection, but sets are disjoint")
+                        .add_splitter()
+                        .add_fact(Fact::new("expected", format!("{:?}", expected)))
+                        .add_fact(Fact::new("actual", format!("{:?}", self.actual())))
+                        .do_fail()
+                } else {
+                    self.new_result().do_ok()
+                }
+            }
+        }
+    };
+}
+
+impl_set_assertion!(HashSet, Eq + Hash);
+impl_set_assertion!(BTreeSet, Ord);
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, HashSet};
+
+    use crate::assertions::testing::AssertionResultAssertion;
+    use crate::testing::CheckThatResult;
+    use crate::{assert_that, check_that, Fact};
+
+    use super::*;
+
+    #[test]
+    fn is_subset_of() {
+        let actual: HashSet<i32> = [1, 2].into_iter().collect();
+        let expected: HashSet<i32> = [1, 2, 3].into_iter().collect();
+        assert_that!(actual).is_subset_of(&expected);
+    }
+
+    #[test]
+    fn is_subset_of_reports_unexpected_elements() {
+        let actual: HashSet<i32> = [1, 2, 3].into_iter().collect();
+        let expected: HashSet<i32> = [1, 2].into_iter().collect();
+        let failed: CheckThatResult = check_that!(actual).is_subset_of(&expected);
+        assert_that!(failed)
+            .facts_are_at_least(vec![Fact::new("unexpected elements", "{3}")]);
+    }
+
+    #[test]
+    fn is_superset_of() {
+        let actual: HashSet<i32> = [1, 2, 3].into_iter().collect();
+        let expected: HashSet<i32> = [1, 2].into_iter().collect();
+        assert_that!(actual).is_superset_of(&expected);
+    }
+
+    #[test]
+    fn is_superset_of_reports_missing_elements() {
+        let actual: HashSet<i32> = [1, 2].into_iter().collect();
+        let expected: HashSet<i32> = [1, 2, 3].into_iter().collect();
+        let failed: CheckThatResult = check_that!(actual).is_superset_of(&expected);
+        assert_that!(failed).facts_are_at_least(vec![Fact::new("missing elements", "{3}")]);
+    }
+
+    #[test]
+    fn contains_all_of() {
+        let actual: HashSet<i32> = [1, 2, 3].into_iter().collect();
+        let expected: HashSet<i32> = [1, 2].into_iter().collect();
+        assert_that!(actual).contains_all_of(&expected);
+    }
+
+    #[test]
+    fn contains_all_of_reports_missing_elements() {
+        let actual: HashSet<i32> = [1, 2].into_iter().collect();
+        let expected: HashSet<i32> = [1, 2, 3].into_iter().collect();
+        let failed: CheckThatResult = check_that!(actual).contains_all_of(&expected);
+        assert_that!(failed).facts_are_at_least(vec![Fact::new("missing elements", "{3}")]);
+    }
+
+    #[test]
+    fn is_disjoint_from() {
+        let actual: HashSet<i32> = [1, 2].into_iter().collect();
+        let expected: HashSet<i32> = [3, 4].into_iter().collect();
+        assert_that!(actual).is_disjoint_from(&expected);
+    }
+
+    #[test]
+    fn is_disjoint_from_reports_unexpected_intersection() {
+        let actual: HashSet<i32> = [1, 2].into_iter().collect();
+        let expected: HashSet<i32> = [2, 3].into_iter().collect();
+        let failed: CheckThatResult = check_that!(actual).is_disjoint_from(&expected);
+        assert_that!(failed)
+            .facts_are_at_least(vec![Fact::new("unexpected intersection", "{2}")]);
+    }
+
+    #[test]
+    fn intersects_with() {
+        let actual: HashSet<i32> = [1, 2].into_iter().collect();
+        let expected: HashSet<i32> = [2, 3].into_iter().collect();
+        assert_that!(actual).intersects_with(&expected);
+    }
+
+    #[test]
+    fn intersects_with_reports_disjoint_sets() {
+        let actual: HashSet<i32> = [1, 2].into_iter().collect();
+        let expected: HashSet<i32> = [3, 4].into_iter().collect();
+        let failed: CheckThatResult = check_that!(actual).intersects_with(&expected);
+        assert_that!(failed).facts_are_at_least(vec![Fact::new_simple_fact(
+            "expected an intersection, but sets are disjoint",
+        )]);
+    }
+
+    #[test]
+    fn btree_set_is_subset_of() {
+        let actual: BTreeSet<i32> = [1, 2].into_iter().collect();
+        let expected: BTreeSet<i32> = [1, 2, 3].into_iter().collect();
+        assert_that!(actual).is_subset_of(&expected);
+    }
+
+    #[test]
+    fn btree_set_is_subset_of_reports_unexpected_elements() {
+        let actual: BTreeSet<i32> = [1, 2, 3].into_iter().collect();
+        let expected: BTreeSet<i32> = [1, 2].into_iter().collect();
+        let failed: CheckThatResult = check_that!(actual).is_subset_of(&expected);
+        assert_that!(failed)
+            .facts_are_at_least(vec![Fact::new("unexpected elements", "{3}")]);
+    }
+}
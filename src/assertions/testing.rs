@@ -1,5 +1,9 @@
 use std::borrow::Borrow;
 use std::collections::HashSet;
+use std::fmt::Debug;
+use std::str::FromStr;
+
+use regex::Regex;
 
 use crate::assertions::iterator::IteratorAssertion;
 use crate::base::{AssertionApi, AssertionResult, Fact, ReturnStrategy, Subject};
@@ -10,7 +14,39 @@ pub trait AssertionResultAssertion<'a, R> {
     fn facts_are_at_least<B: Borrow<Vec<Fact>>>(&self, facts: B) -> R;
     // Returns subject of fact value for the first matched key.
     fn fact_value_for_key<I: Into<String>>(&self, key: I) -> Subject<String, (), R>;
+    // Returns subject of fact value for the first matched key, parsed as `T`.
+    //
+    // Unlike `fact_value_for_key` above, this can fail independently of any
+    // later assertion: a malformed value means there is no `T` to hand back,
+    // so (unlike a normal failing assertion, which still has an `actual` to
+    // report on) there's no way to construct a `Subject<'a, T, (), R>` for
+    // the caller to keep chaining off of. `Result<Subject, R>` keeps that
+    // failure honest instead of panicking or fabricating a `T`; on success
+    // it's a one-time `.unwrap()` before fluent chaining resumes, e.g.
+    // `fact_value_for_key_as::<i32>("count").unwrap().is_greater_than(0)`.
+    //
+    // The key-not-found case is unrelated to this and still `.expect`-panics,
+    // matching `fact_value_for_key`'s existing behavior above; only the
+    // parse-failure path was in scope here.
+    fn fact_value_for_key_as<T: FromStr>(
+        &self,
+        key: impl Into<String>,
+    ) -> Result<Subject<'a, T, (), R>, R>
+    where
+        T::Err: Debug;
     fn fact_keys(&self) -> Subject<'a, HashSet<&String>, (), R>;
+    // Asserts that some fact carries the given key, regardless of its value.
+    fn facts_contain_key<I: Into<String>>(&self, key: I) -> R;
+    // Asserts on the total number of facts, including splitters.
+    fn fact_count_is(&self, count: usize) -> R;
+    // Asserts that at least one fact's value for `key` satisfies `predicate`.
+    fn fact_value_for_key_matching<I: Into<String>, P: Fn(&str) -> bool>(
+        &self,
+        key: I,
+        predicate: P,
+    ) -> R;
+    // Asserts that at least one fact's value for `key` matches `pattern`.
+    fn fact_value_for_key_matching_regex<I: Into<String>>(&self, key: I, pattern: &str) -> R;
 }
 
 fn get_assertion_result<'a, 'o, R>(
@@ -68,6 +104,46 @@ where
         )
     }
 
+    fn fact_value_for_key_as<T: FromStr>(
+        &self,
+        key: impl Into<String>,
+    ) -> Result<Subject<'a, T, (), R>, R>
+    where
+        T::Err: Debug,
+    {
+        let key_str = key.into();
+        let assertion_result = get_assertion_result(&self);
+        // Key-not-found still panics here, same as `fact_value_for_key`
+        // above; only the parse-failure path below is routed through
+        // `AssertionResult` instead of panicking.
+        let raw = assertion_result
+            .facts()
+            .iter()
+            .flat_map(|fact| match fact {
+                Fact::KeyValue { key: k, value } if k.eq(&key_str) => Some(value),
+                _ => None,
+            })
+            .next()
+            .expect(&format!(
+                "key `{}` not found in assertion result.\n{:?}",
+                key_str,
+                assertion_result.generate_message()
+            ));
+        match raw.parse::<T>() {
+            Ok(value) => Ok(self.new_owned_subject(
+                value,
+                Some(format!("{}.[key={}]", self.description_or_expr(), key_str)),
+                (),
+            )),
+            Err(err) => Err(self
+                .new_result()
+                .add_fact(Fact::new("key", &key_str))
+                .add_fact(Fact::new("raw value", format!("{:?}", raw)))
+                .add_fact(Fact::new("parse error", format!("{:?}", err)))
+                .do_fail()),
+        }
+    }
+
     fn fact_keys(&self) -> Subject<HashSet<&String>, (), R> {
         let assertion_result = get_assertion_result(self);
         let keys: HashSet<&String> = assertion_result
@@ -85,6 +161,82 @@ where
             (),
         )
     }
+
+    fn facts_contain_key<I: Into<String>>(&self, key: I) -> R {
+        let key_str = key.into();
+        let assertion_result = get_assertion_result(self);
+        let present_keys: Vec<&String> = assertion_result
+            .facts()
+            .iter()
+            .flat_map(|fact| match fact {
+                Fact::KeyValue { key, .. } => Some(key),
+                _ => None,
+            })
+            .collect();
+        if present_keys.iter().any(|k| k.eq(&&key_str)) {
+            self.new_result().do_ok()
+        } else {
+            self.new_result()
+                .add_fact(Fact::new("expected key", &key_str))
+                .add_fact(Fact::new("present keys", format!("{:?}", present_keys)))
+                .do_fail()
+        }
+    }
+
+    fn fact_count_is(&self, count: usize) -> R {
+        let assertion_result = get_assertion_result(self);
+        let actual_count = assertion_result.facts().len();
+        if actual_count == count {
+            self.new_result().do_ok()
+        } else {
+            self.new_result()
+                .add_fact(Fact::new("expected fact count", count.to_string()))
+                .add_fact(Fact::new("actual fact count", actual_count.to_string()))
+                .do_fail()
+        }
+    }
+
+    fn fact_value_for_key_matching<I: Into<String>, P: Fn(&str) -> bool>(
+        &self,
+        key: I,
+        predicate: P,
+    ) -> R {
+        let key_str = key.into();
+        let assertion_result = get_assertion_result(self);
+        let present_keys: Vec<&String> = assertion_result
+            .facts()
+            .iter()
+            .flat_map(|fact| match fact {
+                Fact::KeyValue { key, .. } => Some(key),
+                _ => None,
+            })
+            .collect();
+        let tested: Vec<(&String, &String)> = assertion_result
+            .facts()
+            .iter()
+            .flat_map(|fact| match fact {
+                Fact::KeyValue { key: k, value } if k.eq(&key_str) => Some((k, value)),
+                _ => None,
+            })
+            .collect();
+        if tested.iter().any(|(_, value)| predicate(value)) {
+            self.new_result().do_ok()
+        } else {
+            self.new_result()
+                .add_fact(Fact::new("expected key", &key_str))
+                .add_fact(Fact::new("present keys", format!("{:?}", present_keys)))
+                .add_fact(Fact::new(
+                    "tested values",
+                    format!("{:?}", tested.iter().map(|(_, v)| v).collect::<Vec<_>>()),
+                ))
+                .do_fail()
+        }
+    }
+
+    fn fact_value_for_key_matching_regex<I: Into<String>>(&self, key: I, pattern: &str) -> R {
+        let regex = Regex::new(pattern).expect("invalid regex pattern");
+        self.fact_value_for_key_matching(key, |value| regex.is_match(value))
+    }
 }
 
 #[cfg(test)]
@@ -125,6 +277,39 @@ mod tests {
             .facts_are(vec![Fact::new_simple_fact("not same")]);
     }
 
+    trait NumericTestAssertion<'a, R> {
+        fn fails_with_number(&self, n: i32) -> R;
+    }
+
+    impl<'a, R> NumericTestAssertion<'a, R> for Subject<'a, &'static str, (), R>
+    where
+        AssertionResult: ReturnStrategy<R>,
+    {
+        fn fails_with_number(&self, n: i32) -> R {
+            self.new_result()
+                .add_fact(Fact::new("count", n.to_string()))
+                .do_fail()
+        }
+    }
+
+    #[test]
+    fn fact_value_for_key_as() {
+        let failed: CheckThatResult = check_that!("actual").fails_with_number(42);
+        assert_that!(failed)
+            .fact_value_for_key_as::<i32>("count")
+            .unwrap()
+            .is_same_to(42);
+    }
+
+    #[test]
+    fn fact_value_for_key_as_reports_parse_failure_instead_of_panicking() {
+        let failed: CheckThatResult = check_that!("actual").fails_with_number(42);
+        let parse_failure: CheckThatResult = check_that!(failed)
+            .fact_value_for_key_as::<bool>("count")
+            .unwrap_err();
+        assert_that!(parse_failure).facts_are_at_least(vec![Fact::new("key", "count")]);
+    }
+
     #[test]
     fn facts_are() {
         let failed: CheckThatResult = check_that!("actual").is_same_to("expected");
@@ -136,4 +321,37 @@ mod tests {
             Fact::new("actual", r#"[Value { value: "not same" }]"#),
         ]);
     }
+
+    #[test]
+    fn facts_contain_key() {
+        let failed: CheckThatResult = check_that!("actual").fails_with_number(42);
+        assert_that!(failed).facts_contain_key("count");
+    }
+
+    #[test]
+    fn fact_count_is() {
+        let failed: CheckThatResult = check_that!("actual").fails_with_number(42);
+        assert_that!(failed).fact_count_is(1);
+    }
+
+    #[test]
+    fn fact_value_for_key_matching() {
+        let failed: CheckThatResult = check_that!("actual").fails_with_number(42);
+        assert_that!(failed).fact_value_for_key_matching("count", |value| value == "42");
+    }
+
+    #[test]
+    fn fact_value_for_key_matching_reports_present_keys_on_failure() {
+        let failed: CheckThatResult = check_that!("actual").fails_with_number(42);
+        let mismatch: CheckThatResult =
+            check_that!(failed).fact_value_for_key_matching("count", |value| value == "7");
+        assert_that!(mismatch)
+            .facts_are_at_least(vec![Fact::new("present keys", r#"["count"]"#)]);
+    }
+
+    #[test]
+    fn fact_value_for_key_matching_regex() {
+        let failed: CheckThatResult = check_that!("actual").fails_with_number(42);
+        assert_that!(failed).fact_value_for_key_matching_regex("count", "^4[0-9]$");
+    }
 }
@@ -0,0 +1,3 @@
+pub mod assertions;
+pub mod matcher;
+pub mod soft;
@@ -0,0 +1,354 @@
+use std::fmt::Debug;
+
+use crate::base::{AssertionApi, AssertionResult, Fact, ReturnStrategy, Subject};
+use crate::testing::CheckThatResult;
+
+/// A reusable, composable check against a value of type `T`.
+///
+/// Unlike the assertion traits elsewhere in this crate, a `Matcher` is a
+/// first-class value: build it once (e.g. `eq(42).or(eq(7))`) and reuse it
+/// across many subjects, or pass it into [`Subject::matches`] (via
+/// [`MatcherAssertion`]).
+pub trait Matcher<T> {
+    /// Checks `actual` against this matcher, returning `Ok(())` if it
+    /// matches, or `Err` with the facts describing why it didn't.
+    fn check(&self, actual: &T) -> CheckThatResult;
+
+    /// A short, human readable description of what this matcher expects,
+    /// used to build the `expected` fact of composed matchers.
+    fn describe(&self) -> String;
+
+    /// Combines two matchers: matches only if both do.
+    fn and<M: Matcher<T>>(self, other: M) -> And<Self, M>
+    where
+        Self: Sized,
+    {
+        And(self, other)
+    }
+
+    /// Combines two matchers: matches if either does.
+    fn or<M: Matcher<T>>(self, other: M) -> Or<Self, M>
+    where
+        Self: Sized,
+    {
+        Or(self, other)
+    }
+
+    /// Inverts this matcher: matches if the wrapped matcher doesn't.
+    fn not(self) -> Not<Self>
+    where
+        Self: Sized,
+    {
+        Not(self)
+    }
+}
+
+// `Matcher::check` has no `Subject` to build off of (it only gets `&T`), so
+// this goes through the same `new_result()`/`add_fact` builder every other
+// assertion in the crate uses, rooted at a throwaway unit subject, rather
+// than relying on `AssertionResult::new` being public API.
+fn fail(facts: Vec<Fact>) -> CheckThatResult {
+    let mut result = Subject::new((), None, ()).new_result();
+    for fact in facts {
+        result = result.add_fact(fact);
+    }
+    result.do_fail()
+}
+
+/// Matches when `actual` equals `expected`. See [`eq`].
+pub struct EqMatcher<T>(T);
+
+/// Builds a [`Matcher`] that matches when the actual value equals `expected`.
+pub fn eq<T>(expected: T) -> EqMatcher<T> {
+    EqMatcher(expected)
+}
+
+impl<T: PartialEq + Debug> Matcher<T> for EqMatcher<T> {
+    fn check(&self, actual: &T) -> CheckThatResult {
+        if actual.eq(&self.0) {
+            Ok(())
+        } else {
+            fail(vec![
+                Fact::new("expected", format!("{:?}", self.0)),
+                Fact::new("actual", format!("{:?}", actual)),
+            ])
+        }
+    }
+
+    fn describe(&self) -> String {
+        format!("{:?}", self.0)
+    }
+}
+
+/// Matches when `actual` falls within `[lower, upper]`. See [`in_range`].
+pub struct InRangeMatcher<T> {
+    lower: T,
+    upper: T,
+}
+
+/// Builds a [`Matcher`] that matches when the actual value falls within
+/// `[lower, upper]` (inclusive).
+pub fn in_range<T>(lower: T, upper: T) -> InRangeMatcher<T> {
+    InRangeMatcher { lower, upper }
+}
+
+impl<T: PartialOrd + Debug> Matcher<T> for InRangeMatcher<T> {
+    fn check(&self, actual: &T) -> CheckThatResult {
+        if *actual >= self.lower && *actual <= self.upper {
+            Ok(())
+        } else {
+            fail(vec![
+                Fact::new("expected", self.describe()),
+                Fact::new("actual", format!("{:?}", actual)),
+            ])
+        }
+    }
+
+    fn describe(&self) -> String {
+        format!("in range {:?}..={:?}", self.lower, self.upper)
+    }
+}
+
+/// Matches when the wrapped predicate returns `true`. See [`predicate`].
+pub struct PredicateMatcher<F> {
+    description: String,
+    predicate: F,
+}
+
+/// Builds a [`Matcher`] from a predicate closure. `description` is used to
+/// build the `expected` fact on failure, e.g. `predicate("even", |n: &i32| n % 2 == 0)`.
+pub fn predicate<T, F: Fn(&T) -> bool>(
+    description: impl Into<String>,
+    predicate: F,
+) -> PredicateMatcher<F> {
+    PredicateMatcher {
+        description: description.into(),
+        predicate,
+    }
+}
+
+impl<T: Debug, F: Fn(&T) -> bool> Matcher<T> for PredicateMatcher<F> {
+    fn check(&self, actual: &T) -> CheckThatResult {
+        if (self.predicate)(actual) {
+            Ok(())
+        } else {
+            fail(vec![
+                Fact::new("expected to satisfy", self.description.clone()),
+                Fact::new("actual", format!("{:?}", actual)),
+            ])
+        }
+    }
+
+    fn describe(&self) -> String {
+        self.description.clone()
+    }
+}
+
+/// Matches when both wrapped matchers match. See [`Matcher::and`].
+pub struct And<A, B>(A, B);
+
+impl<T, A: Matcher<T>, B: Matcher<T>> Matcher<T> for And<A, B> {
+    fn check(&self, actual: &T) -> CheckThatResult {
+        self.0.check(actual)?;
+        self.1.check(actual)
+    }
+
+    fn describe(&self) -> String {
+        format!("{} and {}", self.0.describe(), self.1.describe())
+    }
+}
+
+/// Matches when either wrapped matcher matches. See [`Matcher::or`].
+pub struct Or<A, B>(A, B);
+
+impl<T, A: Matcher<T>, B: Matcher<T>> Matcher<T> for Or<A, B> {
+    fn check(&self, actual: &T) -> CheckThatResult {
+        match (self.0.check(actual), self.1.check(actual)) {
+            (Ok(()), _) | (_, Ok(())) => Ok(()),
+            (Err(left), Err(right)) => {
+                let mut facts = left.facts().clone();
+                facts.push(Fact::new_splitter());
+                facts.extend(right.facts().iter().cloned());
+                fail(facts)
+            }
+        }
+    }
+
+    fn describe(&self) -> String {
+        format!("{} or {}", self.0.describe(), self.1.describe())
+    }
+}
+
+/// Inverts a matcher. See [`Matcher::not`].
+pub struct Not<M>(M);
+
+impl<T: Debug, M: Matcher<T>> Matcher<T> for Not<M> {
+    fn check(&self, actual: &T) -> CheckThatResult {
+        match self.0.check(actual) {
+            Ok(()) => fail(vec![
+                Fact::new("expected", self.describe()),
+                Fact::new("actual", format!("{:?}", actual)),
+            ]),
+            Err(_) => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> String {
+        format!("not {}", self.0.describe())
+    }
+}
+
+/// Matches when every matcher in `matchers` matches.
+pub fn all_of<T>(matchers: Vec<Box<dyn Matcher<T>>>) -> AllOf<T> {
+    AllOf(matchers)
+}
+
+/// Matches when every wrapped matcher matches. See [`all_of`].
+pub struct AllOf<T>(Vec<Box<dyn Matcher<T>>>);
+
+impl<T> Matcher<T> for AllOf<T> {
+    fn check(&self, actual: &T) -> CheckThatResult {
+        for matcher in &self.0 {
+            matcher.check(actual)?;
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> String {
+        self.0
+            .iter()
+            .map(|matcher| matcher.describe())
+            .collect::<Vec<_>>()
+            .join(" and ")
+    }
+}
+
+/// Matches when at least one matcher in `matchers` matches.
+pub fn any_of<T>(matchers: Vec<Box<dyn Matcher<T>>>) -> AnyOf<T> {
+    AnyOf(matchers)
+}
+
+/// Matches when at least one wrapped matcher matches. See [`any_of`].
+pub struct AnyOf<T>(Vec<Box<dyn Matcher<T>>>);
+
+impl<T> Matcher<T> for AnyOf<T> {
+    fn check(&self, actual: &T) -> CheckThatResult {
+        let mut facts = Vec::new();
+        for (i, matcher) in self.0.iter().enumerate() {
+            match matcher.check(actual) {
+                Ok(()) => return Ok(()),
+                Err(failure) => {
+                    if i > 0 {
+                        facts.push(Fact::new_splitter());
+                    }
+                    facts.extend(failure.facts().iter().cloned());
+                }
+            }
+        }
+        fail(facts)
+    }
+
+    fn describe(&self) -> String {
+        self.0
+            .iter()
+            .map(|matcher| matcher.describe())
+            .collect::<Vec<_>>()
+            .join(" or ")
+    }
+}
+
+/// Lets a [`Subject`] be checked against a reusable [`Matcher`].
+pub trait MatcherAssertion<'a, T, R> {
+    fn matches(&self, m: impl Matcher<T>) -> R;
+}
+
+impl<'a, T, R> MatcherAssertion<'a, T, R> for Subject<'a, T, (), R>
+where
+    AssertionResult: ReturnStrategy<R>,
+{
+    fn matches(&self, m: impl Matcher<T>) -> R {
+        match m.check(self.actual()) {
+            Ok(()) => self.new_result().do_ok(),
+            Err(failure) => {
+                let mut result = self.new_result();
+                for fact in failure.facts().iter().cloned() {
+                    result = result.add_fact(fact);
+                }
+                result.do_fail()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::testing::AssertionResultAssertion;
+    use crate::{assert_that, check_that, Fact};
+
+    use super::*;
+
+    #[test]
+    fn eq_matches() {
+        assert_that!(1).matches(eq(1));
+    }
+
+    #[test]
+    fn and_or_not() {
+        assert_that!(5).matches(eq(5).and(in_range(0, 10)));
+        assert_that!(5).matches(eq(1).or(eq(5)));
+        assert_that!(5).matches(eq(1).not());
+    }
+
+    #[test]
+    fn and_fails_with_first_failing_branchs_facts() {
+        let failed: CheckThatResult = check_that!(5).matches(eq(1).and(in_range(0, 10)));
+        assert_that!(failed)
+            .facts_are(vec![Fact::new("expected", "1"), Fact::new("actual", "5")]);
+    }
+
+    #[test]
+    fn or_merges_every_failing_branchs_facts_under_a_splitter() {
+        let failed: CheckThatResult = check_that!(5).matches(eq(1).or(eq(2)));
+        assert_that!(failed).facts_are(vec![
+            Fact::new("expected", "1"),
+            Fact::new("actual", "5"),
+            Fact::new_splitter(),
+            Fact::new("expected", "2"),
+            Fact::new("actual", "5"),
+        ]);
+    }
+
+    #[test]
+    fn not_rewrites_expected_to_say_not() {
+        let failed: CheckThatResult = check_that!(5).matches(eq(5).not());
+        assert_that!(failed)
+            .facts_are(vec![Fact::new("expected", "not 5"), Fact::new("actual", "5")]);
+    }
+
+    #[test]
+    fn all_of_any_of() {
+        assert_that!(5).matches(all_of(vec![
+            Box::new(in_range(0, 10)) as Box<dyn Matcher<i32>>,
+            Box::new(predicate("odd", |n: &i32| n % 2 == 1)),
+        ]));
+        assert_that!(5).matches(any_of(vec![
+            Box::new(eq(1)) as Box<dyn Matcher<i32>>,
+            Box::new(eq(5)),
+        ]));
+    }
+
+    #[test]
+    fn any_of_merges_every_failing_branchs_facts_under_a_splitter() {
+        let failed: CheckThatResult = check_that!(5).matches(any_of(vec![
+            Box::new(eq(1)) as Box<dyn Matcher<i32>>,
+            Box::new(eq(2)),
+        ]));
+        assert_that!(failed).facts_are(vec![
+            Fact::new("expected", "1"),
+            Fact::new("actual", "5"),
+            Fact::new_splitter(),
+            Fact::new("expected", "2"),
+            Fact::new("actual", "5"),
+        ]);
+    }
+}
@@ -0,0 +1,128 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::base::{AssertionApi, AssertionResult, Fact, ReturnStrategy, Subject};
+
+thread_local! {
+    // The collectors of every `SoftAssertions` currently in scope, innermost
+    // (i.e. most recently constructed) last. A failing assertion on any
+    // subject produced by `SoftAssertions::that` pushes onto whichever
+    // collector is on top.
+    static CURRENT: RefCell<Vec<Rc<RefCell<Vec<AssertionResult>>>>> = RefCell::new(Vec::new());
+}
+
+/// Marks a [`Subject`] produced by [`SoftAssertions::that`]. A failing
+/// assertion on such a subject is pushed onto the enclosing
+/// [`SoftAssertions`] collector instead of panicking or returning early.
+pub struct Soft(());
+
+impl ReturnStrategy<Soft> for AssertionResult {
+    fn do_ok(self) -> Soft {
+        Soft(())
+    }
+
+    fn do_fail(self) -> Soft {
+        CURRENT.with(|current| {
+            if let Some(collector) = current.borrow().last() {
+                collector.borrow_mut().push(self);
+            }
+        });
+        Soft(())
+    }
+}
+
+/// A collector of [`AssertionResult`]s that lets many assertions run to
+/// completion before a single, combined failure is reported.
+///
+/// ```ignore
+/// let soft = SoftAssertions::new();
+/// soft.that(1).is_equal_to(2);
+/// soft.that("a").is_equal_to("b");
+/// soft.assert_all(); // panics once, reporting both failures
+/// ```
+pub struct SoftAssertions {
+    results: Rc<RefCell<Vec<AssertionResult>>>,
+}
+
+impl SoftAssertions {
+    /// Creates a new collector and registers it as the current target for
+    /// [`Soft`] failures for as long as it stays alive (see `Drop`).
+    pub fn new() -> Self {
+        let results = Rc::new(RefCell::new(Vec::new()));
+        CURRENT.with(|current| current.borrow_mut().push(results.clone()));
+        Self { results }
+    }
+
+    /// Starts a soft assertion on `actual`. Any failure produced by
+    /// chaining an assertion off of the returned subject is recorded
+    /// rather than panicking immediately. The subject holds no handle to
+    /// this collector: the failure goes to the most recently created
+    /// `SoftAssertions` still alive on this thread, which is this one
+    /// unless another was created after it.
+    pub fn that<'a, S>(&self, actual: S) -> Subject<'a, S, (), Soft> {
+        Subject::new(actual, None, ())
+    }
+
+    /// Merges every failure collected so far into a single
+    /// [`AssertionResult`] and panics with it, numbering each sub-failure
+    /// and separating them with [`Fact::Splitter`]. Does nothing if no
+    /// assertion has failed.
+    pub fn assert_all(&self) {
+        let failures = self.results.borrow_mut().split_off(0);
+        if failures.is_empty() {
+            return;
+        }
+        let mut result = Subject::new((), None, ()).new_result();
+        for (i, failure) in failures.iter().enumerate() {
+            if i > 0 {
+                result = result.add_splitter();
+            }
+            result = result.add_simple_fact(format!("failure {}", i + 1));
+            for fact in failure.facts().iter().cloned() {
+                result = result.add_fact(fact);
+            }
+        }
+        result.do_fail()
+    }
+}
+
+impl Default for SoftAssertions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for SoftAssertions {
+    fn drop(&mut self) {
+        CURRENT.with(|current| {
+            current.borrow_mut().pop();
+        });
+        // Don't double-panic if we're already unwinding from another panic
+        // (e.g. a prior `assert_all()` call, or an unrelated failure).
+        if !std::thread::panicking() {
+            self.assert_all();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn passes_without_panicking() {
+        let soft = SoftAssertions::new();
+        soft.that(1).is_equal_to(1);
+        soft.that("a").is_equal_to("a");
+        soft.assert_all();
+    }
+
+    #[test]
+    #[should_panic(expected = "failure 2")]
+    fn collects_every_failure_before_reporting() {
+        let soft = SoftAssertions::new();
+        soft.that(1).is_equal_to(2);
+        soft.that("a").is_equal_to("b");
+        soft.assert_all();
+    }
+}